@@ -0,0 +1,33 @@
+/// Seed prefixes for PDA derivation. Keeping these centralized avoids typos
+/// between the instruction that derives a PDA and the one that later loads it.
+pub const USERNAME_SEED: &[u8] = b"username";
+pub const GROUP_SEED: &[u8] = b"group";
+
+/// Purpose labels for `derive_session_key`, mixed into the KDF input so a
+/// session key derived for encryption can't be reused as an auth key.
+pub const SESSION_KEY_LABEL_ENCRYPTION: u8 = 0x00;
+pub const SESSION_KEY_LABEL_AUTH: u8 = 0x01;
+pub const SESSION_KEY_LABEL_SALT: u8 = 0x02;
+
+pub const RECOVERY_SEED: &[u8] = b"recovery";
+
+/// Upper bound on how far into the future a `propose_recovery` expiry can be
+/// set, so a malicious proposal can't squat the single recovery PDA for an
+/// account indefinitely (Solana slots run at roughly 2-3 per second, so this
+/// is on the order of a week).
+pub const MAX_RECOVERY_WINDOW_SLOTS: u64 = 1_500_000;
+
+pub const OWNER_INDEX_SEED: &[u8] = b"owner_index";
+pub const DIRECTORY_SEED: &[u8] = b"directory";
+pub const DIRECTORY_ENTRY_SEED: &[u8] = b"directory_entry";
+
+/// Page size cap for `list_usernames` so a single call can't be asked to
+/// walk an unbounded number of directory entries.
+pub const MAX_LIST_PAGE_SIZE: u64 = 50;
+
+/// Anchor account discriminator length, used when hand-computing `LEN` consts.
+pub const DISCRIMINATOR_LENGTH: usize = 8;
+
+/// Number of historical encryption keys kept per username account so that
+/// messages encrypted under a since-rotated key can still be decrypted.
+pub const MAX_KEY_HISTORY: usize = 8;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum KeyRegistryError {
+    #[msg("Username must be between 1 and 32 characters long")]
+    InvalidUsernameLength,
+    #[msg("Signer is not authorized to perform this action")]
+    Unauthorized,
+    #[msg("No encryption key is recorded for the requested epoch")]
+    EpochNotFound,
+    #[msg("Group name must be between 1 and 32 characters long")]
+    InvalidGroupNameLength,
+    #[msg("Group has reached its maximum member count")]
+    GroupFull,
+    #[msg("Pubkey is already a member of this group")]
+    AlreadyMember,
+    #[msg("Pubkey is not a member of this group")]
+    MemberNotFound,
+    #[msg("Recovery threshold must be between 1 and the number of guardians")]
+    InvalidRecoveryThreshold,
+    #[msg("Too many guardians for the space reserved on this account")]
+    TooManyGuardians,
+    #[msg("This account has no recovery guardians configured")]
+    RecoveryNotConfigured,
+    #[msg("Signer is not a registered guardian for this account")]
+    NotAGuardian,
+    #[msg("Guardian has already approved this recovery request")]
+    AlreadyApproved,
+    #[msg("Recovery request has expired")]
+    RecoveryExpired,
+    #[msg("Recovery request has not reached its approval threshold")]
+    ThresholdNotMet,
+    #[msg("Recovery request has not expired yet")]
+    RecoveryNotExpired,
+    #[msg("Expiry slot must be in the future and within the maximum recovery window")]
+    InvalidExpirySlot,
+    #[msg("Requested page size exceeds the maximum allowed")]
+    PageSizeTooLarge,
+    #[msg("Requested offset is past the end of the directory")]
+    OffsetOutOfRange,
+    #[msg("Directory entry account does not match the expected index")]
+    InvalidDirectoryEntry,
+}
@@ -0,0 +1,122 @@
+use crate::state::UsernameAccount;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+/// Emitted on every derivation so a client watching the account can learn a
+/// tag was computed for this `(peer, label, counter)` without re-deriving
+/// it. `session_value` is included deliberately: it is derived entirely
+/// from data already public on-chain (`encryption_key` and both pubkeys),
+/// so withholding it from the event would cost legitimate callers a round
+/// trip while giving up nothing, since any observer could recompute it
+/// off-chain anyway.
+#[event]
+pub struct SessionKeyDerived {
+    pub username_account: Pubkey,
+    pub peer: Pubkey,
+    pub label: u8,
+    pub counter: u64,
+    pub session_value: [u8; 32],
+}
+
+#[derive(Accounts)]
+pub struct DeriveSessionKey<'info> {
+    pub username_account: Account<'info, UsernameAccount>,
+}
+
+/// Computes `H(master_key || label || counter || sorted(pubkey_a, pubkey_b))`
+/// so both participants land on the same value regardless of who calls this.
+///
+/// This value is **public, not secret**: `master_key` is `encryption_key`, a
+/// plain field on `UsernameAccount` readable by anyone, and `peer`/`owner`
+/// are public pubkeys. Anyone can recompute `session_value` off-chain
+/// without ever calling this instruction — calling it only costs compute
+/// budget to save a client the trouble of hashing locally. It must not be
+/// used as encryption key material; real end-to-end confidentiality
+/// requires a key agreement (e.g. ECDH) over private scalars that never
+/// touch the chain.
+pub fn derive_session_key(
+    ctx: Context<DeriveSessionKey>,
+    peer: Pubkey,
+    label: u8,
+    counter: u64,
+) -> Result<[u8; 32]> {
+    let username_account = &ctx.accounts.username_account;
+    let session_value = compute_session_value(
+        username_account.encryption_key,
+        username_account.owner,
+        peer,
+        label,
+        counter,
+    );
+
+    emit!(SessionKeyDerived {
+        username_account: username_account.key(),
+        peer,
+        label,
+        counter,
+        session_value,
+    });
+
+    Ok(session_value)
+}
+
+/// Pure computation behind `derive_session_key`, pulled out so the ordering
+/// and mixing logic can be unit tested without spinning up an account.
+fn compute_session_value(
+    master_key: [u8; 32],
+    owner: Pubkey,
+    peer: Pubkey,
+    label: u8,
+    counter: u64,
+) -> [u8; 32] {
+    let mut participants = [owner, peer];
+    participants.sort();
+
+    let mut input = Vec::with_capacity(32 + 1 + 8 + 32 + 32);
+    input.extend_from_slice(&master_key);
+    input.push(label);
+    input.extend_from_slice(&counter.to_le_bytes());
+    input.extend_from_slice(participants[0].as_ref());
+    input.extend_from_slice(participants[1].as_ref());
+
+    hash(&input).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derivation_is_order_independent_for_participants() {
+        let master = [7u8; 32];
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        assert_eq!(
+            compute_session_value(master, a, b, 0, 1),
+            compute_session_value(master, b, a, 0, 1)
+        );
+    }
+
+    #[test]
+    fn different_labels_or_counters_yield_different_values() {
+        let master = [7u8; 32];
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        let base = compute_session_value(master, a, b, 0, 1);
+        assert_ne!(base, compute_session_value(master, a, b, 1, 1));
+        assert_ne!(base, compute_session_value(master, a, b, 0, 2));
+    }
+
+    #[test]
+    fn different_master_keys_yield_different_values() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        assert_ne!(
+            compute_session_value([1u8; 32], a, b, 0, 1),
+            compute_session_value([2u8; 32], a, b, 0, 1)
+        );
+    }
+}
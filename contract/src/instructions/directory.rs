@@ -0,0 +1,123 @@
+use crate::constants::{DIRECTORY_ENTRY_SEED, DIRECTORY_SEED, MAX_LIST_PAGE_SIZE, OWNER_INDEX_SEED};
+use crate::errors::KeyRegistryError;
+use crate::state::{Directory, DirectoryEntry, OwnerIndex, UsernameAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct LookupByOwner<'info> {
+    #[account(seeds = [OWNER_INDEX_SEED, owner_index.owner.as_ref()], bump = owner_index.bump)]
+    pub owner_index: Account<'info, OwnerIndex>,
+}
+
+pub fn lookup_by_owner(ctx: Context<LookupByOwner>) -> Result<Pubkey> {
+    let owner_index = &ctx.accounts.owner_index;
+    msg!(
+        "owner: {}, username_account: {}",
+        owner_index.owner,
+        owner_index.username_account
+    );
+    Ok(owner_index.username_account)
+}
+
+/// One page of the registry, mirroring `DirectoryEntry` but shaped for
+/// returning to a client instead of for on-chain storage.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ListedUsername {
+    pub index: u64,
+    pub username_account: Pubkey,
+    pub owner: Pubkey,
+    pub tombstoned: bool,
+}
+
+#[derive(Accounts)]
+pub struct ListUsernames<'info> {
+    #[account(seeds = [DIRECTORY_SEED], bump = directory.bump)]
+    pub directory: Account<'info, Directory>,
+    // Remaining accounts: the `DirectoryEntry` PDAs for `offset..offset+limit`,
+    // in order, as derived by the client from `DIRECTORY_ENTRY_SEED`.
+}
+
+/// Pages through the append-only directory starting at `offset`, returning
+/// up to `limit` entries (a `limit` above `MAX_LIST_PAGE_SIZE` is rejected
+/// with `PageSizeTooLarge`). The caller
+/// supplies the `DirectoryEntry` accounts for that range as remaining
+/// accounts since Anchor instructions can't enumerate PDAs on their own;
+/// tombstoned entries are still returned so offsets stay stable across
+/// deregistrations.
+pub fn list_usernames(ctx: Context<ListUsernames>, offset: u64, limit: u64) -> Result<Vec<ListedUsername>> {
+    require!(limit <= MAX_LIST_PAGE_SIZE, KeyRegistryError::PageSizeTooLarge);
+    require!(
+        offset <= ctx.accounts.directory.register_cursor,
+        KeyRegistryError::OffsetOutOfRange
+    );
+
+    let end = ctx.accounts.directory.page_end(offset, limit);
+    let expected = (end - offset) as usize;
+    require!(
+        ctx.remaining_accounts.len() == expected,
+        KeyRegistryError::InvalidDirectoryEntry
+    );
+
+    let mut page = Vec::with_capacity(expected);
+    for (i, account_info) in ctx.remaining_accounts.iter().enumerate() {
+        let index = offset + i as u64;
+        let entry: Account<DirectoryEntry> = Account::try_from(account_info)?;
+
+        // `create_program_address` just hashes the given bump instead of
+        // brute-forcing one like `find_program_address` would, so this stays
+        // cheap even across a full `MAX_LIST_PAGE_SIZE` page.
+        let expected_key = Pubkey::create_program_address(
+            &[
+                DIRECTORY_ENTRY_SEED,
+                index.to_le_bytes().as_ref(),
+                &[entry.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| KeyRegistryError::InvalidDirectoryEntry)?;
+        require_keys_eq!(
+            *account_info.key,
+            expected_key,
+            KeyRegistryError::InvalidDirectoryEntry
+        );
+        require_eq!(entry.index, index, KeyRegistryError::InvalidDirectoryEntry);
+
+        page.push(ListedUsername {
+            index: entry.index,
+            username_account: entry.username_account,
+            owner: entry.owner,
+            tombstoned: entry.tombstoned,
+        });
+    }
+
+    Ok(page)
+}
+
+#[derive(Accounts)]
+pub struct DeregisterUsername<'info> {
+    #[account(mut, has_one = owner @ KeyRegistryError::Unauthorized, close = owner)]
+    pub username_account: Account<'info, UsernameAccount>,
+
+    #[account(mut, has_one = owner @ KeyRegistryError::Unauthorized, close = owner)]
+    pub owner_index: Account<'info, OwnerIndex>,
+
+    #[account(mut, constraint = directory_entry.username_account == username_account.key())]
+    pub directory_entry: Account<'info, DirectoryEntry>,
+
+    #[account(mut, seeds = [DIRECTORY_SEED], bump = directory.bump)]
+    pub directory: Account<'info, Directory>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+/// Closes the username account and its reverse index, but only tombstones
+/// the directory entry rather than closing it, so `list_usernames` offsets
+/// downstream of this entry don't shift. `directory.count` drops to reflect
+/// the live registration total; `register_cursor`, which pagination bounds
+/// are computed from, is untouched so existing indices keep working.
+pub fn deregister_username(ctx: Context<DeregisterUsername>) -> Result<()> {
+    ctx.accounts.directory_entry.tombstoned = true;
+    ctx.accounts.directory.count = ctx.accounts.directory.count.checked_sub(1).unwrap();
+    Ok(())
+}
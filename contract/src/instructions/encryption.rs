@@ -0,0 +1,49 @@
+use crate::errors::KeyRegistryError;
+use crate::state::UsernameAccount;
+use anchor_lang::prelude::*;
+
+/// Emitted whenever a username's encryption key rotates. Carries the new
+/// epoch and activation slot but not the key itself; peers who need the
+/// key read it from the account or call `lookup_key_at_epoch`.
+#[event]
+pub struct KeyRotated {
+    pub username_account: Pubkey,
+    pub owner: Pubkey,
+    pub new_epoch: u64,
+    pub activated_at_slot: u64,
+}
+
+#[derive(Accounts)]
+pub struct UpdateEncryptionKey<'info> {
+    #[account(mut, has_one = owner @ KeyRegistryError::Unauthorized)]
+    pub username_account: Account<'info, UsernameAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn update_encryption_key(ctx: Context<UpdateEncryptionKey>, new_key: [u8; 32]) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let username_account = &mut ctx.accounts.username_account;
+    username_account.rotate_key(new_key, slot);
+
+    emit!(KeyRotated {
+        username_account: username_account.key(),
+        owner: username_account.owner,
+        new_epoch: username_account.key_epoch,
+        activated_at_slot: slot,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LookupKeyAtEpoch<'info> {
+    pub username_account: Account<'info, UsernameAccount>,
+}
+
+pub fn lookup_key_at_epoch(ctx: Context<LookupKeyAtEpoch>, epoch: u64) -> Result<[u8; 32]> {
+    ctx.accounts
+        .username_account
+        .key_at_epoch(epoch)
+        .ok_or_else(|| error!(KeyRegistryError::EpochNotFound))
+}
@@ -0,0 +1,130 @@
+use crate::constants::GROUP_SEED;
+use crate::errors::KeyRegistryError;
+use crate::state::{Group, Member, Rights};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateGroup<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Group::LEN,
+        seeds = [GROUP_SEED, creator.key().as_ref(), name.as_bytes()],
+        bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_group(ctx: Context<CreateGroup>, name: String, group_key: [u8; 32]) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= Group::MAX_NAME_LEN,
+        KeyRegistryError::InvalidGroupNameLength
+    );
+
+    let group = &mut ctx.accounts.group;
+    group.creator = ctx.accounts.creator.key();
+    group.name = name;
+    group.group_key = group_key;
+    group.key_epoch = 0;
+    group.members = vec![Member {
+        pubkey: ctx.accounts.creator.key(),
+        rights: Rights::Owner,
+    }];
+    group.bump = ctx.bumps.group;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddMember<'info> {
+    #[account(mut)]
+    pub group: Account<'info, Group>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn add_member(ctx: Context<AddMember>, member: Pubkey, rights: Rights) -> Result<()> {
+    let group = &mut ctx.accounts.group;
+    let admin_rights = group.rights_of(&ctx.accounts.admin.key());
+    require!(admin_rights.at_least(Rights::Admin), KeyRegistryError::Unauthorized);
+    require!(admin_rights.can_modify(rights), KeyRegistryError::Unauthorized);
+    require!(
+        group.members.iter().all(|m| m.pubkey != member),
+        KeyRegistryError::AlreadyMember
+    );
+    require!(
+        group.members.len() < Group::MAX_MEMBERS,
+        KeyRegistryError::GroupFull
+    );
+
+    group.members.push(Member {
+        pubkey: member,
+        rights,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMemberRights<'info> {
+    #[account(mut)]
+    pub group: Account<'info, Group>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn set_member_rights(ctx: Context<SetMemberRights>, member: Pubkey, rights: Rights) -> Result<()> {
+    let group = &mut ctx.accounts.group;
+    let admin_rights = group.rights_of(&ctx.accounts.admin.key());
+    require!(admin_rights.at_least(Rights::Admin), KeyRegistryError::Unauthorized);
+
+    let entry = group
+        .members
+        .iter_mut()
+        .find(|m| m.pubkey == member)
+        .ok_or(KeyRegistryError::MemberNotFound)?;
+    require!(admin_rights.can_modify(entry.rights), KeyRegistryError::Unauthorized);
+    require!(admin_rights.can_modify(rights), KeyRegistryError::Unauthorized);
+    entry.rights = rights;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveMember<'info> {
+    #[account(mut)]
+    pub group: Account<'info, Group>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Removing a member bumps the group key epoch so the group's shared key can
+/// be rotated out from under them; `group_key` is the new key the remaining
+/// members have agreed on off-chain before calling this instruction.
+pub fn remove_member(ctx: Context<RemoveMember>, member: Pubkey, group_key: [u8; 32]) -> Result<()> {
+    let group = &mut ctx.accounts.group;
+    let admin_rights = group.rights_of(&ctx.accounts.admin.key());
+    require!(admin_rights.at_least(Rights::Admin), KeyRegistryError::Unauthorized);
+
+    let index = group
+        .members
+        .iter()
+        .position(|m| m.pubkey == member)
+        .ok_or(KeyRegistryError::MemberNotFound)?;
+    require!(
+        admin_rights.can_modify(group.members[index].rights),
+        KeyRegistryError::Unauthorized
+    );
+    group.members.remove(index);
+
+    group.group_key = group_key;
+    group.key_epoch = group.key_epoch.checked_add(1).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+pub mod derivation;
+pub mod directory;
+pub mod encryption;
+pub mod groups;
+pub mod recovery;
+pub mod username;
+
+pub use derivation::*;
+pub use directory::*;
+pub use encryption::*;
+pub use groups::*;
+pub use recovery::*;
+pub use username::*;
@@ -0,0 +1,220 @@
+use crate::constants::{MAX_RECOVERY_WINDOW_SLOTS, RECOVERY_SEED};
+use crate::errors::KeyRegistryError;
+use crate::state::{RecoveryConfig, RecoveryRequest, UsernameAccount, MAX_GUARDIANS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConfigureRecovery<'info> {
+    #[account(mut, has_one = owner @ KeyRegistryError::Unauthorized)]
+    pub username_account: Account<'info, UsernameAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn configure_recovery(
+    ctx: Context<ConfigureRecovery>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        guardians.len() <= MAX_GUARDIANS,
+        KeyRegistryError::TooManyGuardians
+    );
+    require!(
+        RecoveryConfig::is_threshold_valid(guardians.len(), threshold),
+        KeyRegistryError::InvalidRecoveryThreshold
+    );
+
+    ctx.accounts.username_account.recovery_config = Some(RecoveryConfig {
+        guardians,
+        threshold,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeRecovery<'info> {
+    pub username_account: Account<'info, UsernameAccount>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = RecoveryRequest::LEN,
+        seeds = [RECOVERY_SEED, username_account.key().as_ref()],
+        bump,
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_recovery(
+    ctx: Context<ProposeRecovery>,
+    new_encryption_key: [u8; 32],
+    expiry_slot: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.username_account.recovery_config.is_some(),
+        KeyRegistryError::RecoveryNotConfigured
+    );
+
+    let current_slot = Clock::get()?.slot;
+    require!(
+        expiry_slot > current_slot
+            && expiry_slot <= current_slot.saturating_add(MAX_RECOVERY_WINDOW_SLOTS),
+        KeyRegistryError::InvalidExpirySlot
+    );
+
+    let recovery_request = &mut ctx.accounts.recovery_request;
+    recovery_request.username_account = ctx.accounts.username_account.key();
+    recovery_request.new_owner = ctx.accounts.new_owner.key();
+    recovery_request.new_encryption_key = new_encryption_key;
+    recovery_request.approvals = Vec::new();
+    recovery_request.expiry_slot = expiry_slot;
+    recovery_request.bump = ctx.bumps.recovery_request;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    pub username_account: Account<'info, UsernameAccount>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_SEED, username_account.key().as_ref()],
+        bump = recovery_request.bump,
+        has_one = username_account,
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+
+    pub guardian: Signer<'info>,
+}
+
+pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+    let config = ctx
+        .accounts
+        .username_account
+        .recovery_config
+        .as_ref()
+        .ok_or(KeyRegistryError::RecoveryNotConfigured)?;
+
+    require!(
+        config.is_guardian(&ctx.accounts.guardian.key()),
+        KeyRegistryError::NotAGuardian
+    );
+
+    let recovery_request = &mut ctx.accounts.recovery_request;
+    require!(
+        Clock::get()?.slot <= recovery_request.expiry_slot,
+        KeyRegistryError::RecoveryExpired
+    );
+    require!(
+        !recovery_request.approvals.contains(&ctx.accounts.guardian.key()),
+        KeyRegistryError::AlreadyApproved
+    );
+
+    recovery_request.approvals.push(ctx.accounts.guardian.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeRecovery<'info> {
+    #[account(mut)]
+    pub username_account: Account<'info, UsernameAccount>,
+
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [RECOVERY_SEED, username_account.key().as_ref()],
+        bump = recovery_request.bump,
+        has_one = username_account,
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+
+    #[account(mut, address = recovery_request.new_owner)]
+    pub new_owner: Signer<'info>,
+}
+
+pub fn finalize_recovery(ctx: Context<FinalizeRecovery>) -> Result<()> {
+    let recovery_request = &ctx.accounts.recovery_request;
+
+    require!(
+        Clock::get()?.slot <= recovery_request.expiry_slot,
+        KeyRegistryError::RecoveryExpired
+    );
+
+    let config = ctx
+        .accounts
+        .username_account
+        .recovery_config
+        .as_ref()
+        .ok_or(KeyRegistryError::RecoveryNotConfigured)?;
+    // Re-validate against the *current* guardian set rather than trusting
+    // `approvals` as recorded: `configure_recovery` can revoke a guardian
+    // (e.g. a compromised key) while a request is pending, and a stale
+    // approval from a removed guardian must not keep counting toward
+    // `threshold`.
+    require!(
+        recovery_request.has_reached_threshold(config),
+        KeyRegistryError::ThresholdNotMet
+    );
+
+    let username_account = &mut ctx.accounts.username_account;
+    username_account.owner = recovery_request.new_owner;
+    username_account.rotate_key(recovery_request.new_encryption_key, Clock::get()?.slot);
+    username_account.recovery_config = None;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    pub username_account: Account<'info, UsernameAccount>,
+
+    #[account(
+        mut,
+        close = new_owner,
+        seeds = [RECOVERY_SEED, username_account.key().as_ref()],
+        bump = recovery_request.bump,
+        has_one = username_account,
+    )]
+    pub recovery_request: Account<'info, RecoveryRequest>,
+
+    /// CHECK: only used as the rent refund target, and constrained to match
+    /// the request's recorded `new_owner` so cancellation can't redirect
+    /// funds anywhere else.
+    #[account(mut, address = recovery_request.new_owner)]
+    pub new_owner: UncheckedAccount<'info>,
+
+    /// Only checked against when cancelling before expiry: must be the
+    /// account owner or one of its guardians. Once the request has expired,
+    /// cancellation is permissionless and this can be any signer.
+    pub authority: Signer<'info>,
+}
+
+/// Reclaims the rent of a `RecoveryRequest`, either because it expired
+/// before reaching its approval threshold, or early at the request of the
+/// owner or a guardian. Without the early path, a griefer could repeatedly
+/// `propose_recovery` a bogus request against a victim's account and let it
+/// ride out its full expiry window each time, permanently squatting the one
+/// `RecoveryRequest` PDA (seeded only by `username_account`) and denying the
+/// victim's guardians any chance to approve a legitimate request.
+pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+    let expired = Clock::get()?.slot > ctx.accounts.recovery_request.expiry_slot;
+    if !expired {
+        require!(
+            ctx.accounts
+                .username_account
+                .can_cancel_pending_recovery(&ctx.accounts.authority.key()),
+            KeyRegistryError::Unauthorized
+        );
+    }
+
+    Ok(())
+}
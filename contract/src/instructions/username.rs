@@ -0,0 +1,109 @@
+use crate::constants::{DIRECTORY_ENTRY_SEED, DIRECTORY_SEED, OWNER_INDEX_SEED, USERNAME_SEED};
+use crate::state::{Directory, DirectoryEntry, KeyHistoryEntry, OwnerIndex, UsernameAccount};
+use crate::utils::validate_username;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(username: String)]
+pub struct RegisterUsername<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = UsernameAccount::LEN,
+        seeds = [USERNAME_SEED, username.as_bytes()],
+        bump,
+    )]
+    pub username_account: Account<'info, UsernameAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = OwnerIndex::LEN,
+        seeds = [OWNER_INDEX_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub owner_index: Account<'info, OwnerIndex>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = Directory::LEN,
+        seeds = [DIRECTORY_SEED],
+        bump,
+    )]
+    pub directory: Account<'info, Directory>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = DirectoryEntry::LEN,
+        seeds = [DIRECTORY_ENTRY_SEED, directory.register_cursor.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub directory_entry: Account<'info, DirectoryEntry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_username(
+    ctx: Context<RegisterUsername>,
+    username: String,
+    encryption_key: [u8; 32],
+) -> Result<()> {
+    validate_username(&username)?;
+
+    let username_account = &mut ctx.accounts.username_account;
+    username_account.owner = ctx.accounts.owner.key();
+    username_account.username = username;
+    username_account.encryption_key = encryption_key;
+    username_account.key_epoch = 0;
+    username_account.key_history[0] = KeyHistoryEntry {
+        epoch: 0,
+        key: encryption_key,
+        activated_at_slot: Clock::get()?.slot,
+    };
+    username_account.key_history_len = 1;
+    username_account.key_history_head = 0;
+    username_account.recovery_config = None;
+    username_account.bump = ctx.bumps.username_account;
+
+    ctx.accounts.owner_index.owner = ctx.accounts.owner.key();
+    ctx.accounts.owner_index.username_account = username_account.key();
+    ctx.accounts.owner_index.bump = ctx.bumps.owner_index;
+
+    let directory = &mut ctx.accounts.directory;
+    if directory.count == 0 && directory.register_cursor == 0 {
+        directory.bump = ctx.bumps.directory;
+    }
+    let index = directory.register_cursor;
+
+    ctx.accounts.directory_entry.index = index;
+    ctx.accounts.directory_entry.username_account = username_account.key();
+    ctx.accounts.directory_entry.owner = ctx.accounts.owner.key();
+    ctx.accounts.directory_entry.tombstoned = false;
+    ctx.accounts.directory_entry.bump = ctx.bumps.directory_entry;
+
+    directory.count = directory.count.checked_add(1).unwrap();
+    directory.register_cursor = directory.register_cursor.checked_add(1).unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LookupUsername<'info> {
+    pub username_account: Account<'info, UsernameAccount>,
+}
+
+pub fn lookup_username(ctx: Context<LookupUsername>) -> Result<()> {
+    let username_account = &ctx.accounts.username_account;
+    msg!(
+        "username: {}, owner: {}, encryption_key: {:?}",
+        username_account.username,
+        username_account.owner,
+        username_account.encryption_key
+    );
+    Ok(())
+}
@@ -5,6 +5,7 @@ pub mod utils;
 pub mod state;
 pub mod instructions;
 use instructions::*;
+use state::Rights;
 declare_id!("96hG67JxhNEptr1LkdtDcrqvtWiHH3x4GibDBcdh4MYQ");
 #[program]
 pub mod key_registry {
@@ -29,5 +30,88 @@ pub mod key_registry {
         instructions::encryption::update_encryption_key(ctx, new_key)
     }
 
-    //we will be adding variouosh groups later too 
+    pub fn lookup_key_at_epoch(ctx: Context<LookupKeyAtEpoch>, epoch: u64) -> Result<[u8; 32]> {
+        instructions::encryption::lookup_key_at_epoch(ctx, epoch)
+    }
+
+    pub fn create_group(
+        ctx: Context<CreateGroup>,
+        name: String,
+        group_key: [u8; 32],
+    ) -> Result<()> {
+        instructions::groups::create_group(ctx, name, group_key)
+    }
+
+    pub fn add_member(ctx: Context<AddMember>, member: Pubkey, rights: Rights) -> Result<()> {
+        instructions::groups::add_member(ctx, member, rights)
+    }
+
+    pub fn set_member_rights(
+        ctx: Context<SetMemberRights>,
+        member: Pubkey,
+        rights: Rights,
+    ) -> Result<()> {
+        instructions::groups::set_member_rights(ctx, member, rights)
+    }
+
+    pub fn remove_member(
+        ctx: Context<RemoveMember>,
+        member: Pubkey,
+        group_key: [u8; 32],
+    ) -> Result<()> {
+        instructions::groups::remove_member(ctx, member, group_key)
+    }
+
+    pub fn derive_session_key(
+        ctx: Context<DeriveSessionKey>,
+        peer: Pubkey,
+        label: u8,
+        counter: u64,
+    ) -> Result<[u8; 32]> {
+        instructions::derivation::derive_session_key(ctx, peer, label, counter)
+    }
+
+    pub fn configure_recovery(
+        ctx: Context<ConfigureRecovery>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::recovery::configure_recovery(ctx, guardians, threshold)
+    }
+
+    pub fn propose_recovery(
+        ctx: Context<ProposeRecovery>,
+        new_encryption_key: [u8; 32],
+        expiry_slot: u64,
+    ) -> Result<()> {
+        instructions::recovery::propose_recovery(ctx, new_encryption_key, expiry_slot)
+    }
+
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        instructions::recovery::approve_recovery(ctx)
+    }
+
+    pub fn finalize_recovery(ctx: Context<FinalizeRecovery>) -> Result<()> {
+        instructions::recovery::finalize_recovery(ctx)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::recovery::cancel_recovery(ctx)
+    }
+
+    pub fn lookup_by_owner(ctx: Context<LookupByOwner>) -> Result<Pubkey> {
+        instructions::directory::lookup_by_owner(ctx)
+    }
+
+    pub fn list_usernames(
+        ctx: Context<ListUsernames>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<ListedUsername>> {
+        instructions::directory::list_usernames(ctx, offset, limit)
+    }
+
+    pub fn deregister_username(ctx: Context<DeregisterUsername>) -> Result<()> {
+        instructions::directory::deregister_username(ctx)
+    }
 }
@@ -0,0 +1,106 @@
+use crate::constants::DISCRIMINATOR_LENGTH;
+use anchor_lang::prelude::*;
+
+/// Reverse index from an owner's pubkey back to the username account they
+/// registered, so a contacts/directory UI can resolve "who is this pubkey"
+/// without scanning every `UsernameAccount`.
+#[account]
+pub struct OwnerIndex {
+    pub owner: Pubkey,
+    pub username_account: Pubkey,
+    pub bump: u8,
+}
+
+impl OwnerIndex {
+    pub const LEN: usize = DISCRIMINATOR_LENGTH + 32 + 32 + 1;
+}
+
+/// Singleton counter for the registry. `count` is the number of *live*
+/// (non-tombstoned) registrations, bumped on `register_username` and
+/// dropped on `deregister_username` — a "how many users" stat for a
+/// contacts/directory UI. `register_cursor` is the total number of
+/// `DirectoryEntry` slots ever allocated; it only ever increases, even
+/// across deregistrations, so pagination bounds must be computed from it
+/// rather than from `count` — otherwise a page could fall short of slots
+/// that still exist (just tombstoned) past the live count.
+#[account]
+pub struct Directory {
+    pub count: u64,
+    pub register_cursor: u64,
+    pub bump: u8,
+}
+
+impl Directory {
+    pub const LEN: usize = DISCRIMINATOR_LENGTH + 8 + 8 + 1;
+
+    /// Computes the exclusive end index of a `list_usernames` page, clamping
+    /// `offset + limit` to `register_cursor` (total slots ever allocated) so
+    /// a page never reaches past a `DirectoryEntry` that doesn't exist yet.
+    pub fn page_end(&self, offset: u64, limit: u64) -> u64 {
+        offset.saturating_add(limit).min(self.register_cursor)
+    }
+}
+
+/// One append-only slot in the directory, indexed by the cursor value it was
+/// created with. `tombstoned` marks a deregistered username without
+/// reclaiming its slot, so offset-based pagination over `list_usernames`
+/// stays stable regardless of deregistrations.
+#[account]
+pub struct DirectoryEntry {
+    pub index: u64,
+    pub username_account: Pubkey,
+    pub owner: Pubkey,
+    pub tombstoned: bool,
+    pub bump: u8,
+}
+
+impl DirectoryEntry {
+    pub const LEN: usize = DISCRIMINATOR_LENGTH + 8 + 32 + 32 + 1 + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directory_with_cursor(register_cursor: u64) -> Directory {
+        Directory {
+            count: register_cursor,
+            register_cursor,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn page_end_clamps_to_register_cursor() {
+        let directory = directory_with_cursor(10);
+        assert_eq!(directory.page_end(0, 5), 5);
+        assert_eq!(directory.page_end(8, 5), 10);
+        assert_eq!(directory.page_end(10, 5), 10);
+    }
+
+    #[test]
+    fn page_end_handles_empty_directory() {
+        let directory = directory_with_cursor(0);
+        assert_eq!(directory.page_end(0, 5), 0);
+    }
+
+    #[test]
+    fn page_end_does_not_overflow_on_saturating_limit() {
+        let directory = directory_with_cursor(3);
+        assert_eq!(directory.page_end(1, u64::MAX), 3);
+    }
+
+    #[test]
+    fn page_end_clamps_to_register_cursor_not_live_count() {
+        // Three slots were ever allocated, but one was later deregistered,
+        // so `count` has dropped below `register_cursor`. Pagination must
+        // still reach index 2, since its (tombstoned) `DirectoryEntry` PDA
+        // still exists and offsets must not shift.
+        let directory = Directory {
+            count: 2,
+            register_cursor: 3,
+            bump: 0,
+        };
+        assert_eq!(directory.page_end(0, 10), 3);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::constants::DISCRIMINATOR_LENGTH;
+use anchor_lang::prelude::*;
+
+/// A member's standing within a group, ordered from least to most privileged
+/// except `Revoked`, which is a terminal state rather than a rung below
+/// `Member`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rights {
+    Revoked,
+    Member,
+    Admin,
+    Owner,
+}
+
+impl Rights {
+    pub fn at_least(&self, other: Rights) -> bool {
+        *self != Rights::Revoked && *self >= other
+    }
+
+    /// Whether a caller holding `self` is allowed to assign `target` to
+    /// someone, or to change a member currently holding `target`. An Owner
+    /// can touch anyone; anyone else must strictly outrank `target`, so an
+    /// Admin can manage Members but not other Admins, Owners, or grant Owner.
+    pub fn can_modify(&self, target: Rights) -> bool {
+        *self == Rights::Owner || *self > target
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct Member {
+    pub pubkey: Pubkey,
+    pub rights: Rights,
+}
+
+impl Member {
+    pub const LEN: usize = 32 + 1;
+}
+
+#[account]
+pub struct Group {
+    pub creator: Pubkey,
+    pub name: String,
+    /// Symmetric key shared by current members; bumped to a new value (by
+    /// the caller, off-chain) whenever a member is removed so the excluded
+    /// member can no longer decrypt future group traffic.
+    pub group_key: [u8; 32],
+    pub key_epoch: u64,
+    pub members: Vec<Member>,
+    pub bump: u8,
+}
+
+impl Group {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_MEMBERS: usize = 64;
+
+    pub const LEN: usize = DISCRIMINATOR_LENGTH
+        + 32 // creator
+        + 4 + Self::MAX_NAME_LEN // name
+        + 32 // group_key
+        + 8 // key_epoch
+        + 4 + Member::LEN * Self::MAX_MEMBERS // members
+        + 1; // bump
+
+    pub fn rights_of(&self, pubkey: &Pubkey) -> Rights {
+        self.members
+            .iter()
+            .find(|m| &m.pubkey == pubkey)
+            .map(|m| m.rights)
+            .unwrap_or(Rights::Revoked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_RIGHTS: [Rights; 4] = [Rights::Revoked, Rights::Member, Rights::Admin, Rights::Owner];
+
+    #[test]
+    fn owner_can_modify_anyone_including_other_owners() {
+        for &target in &ALL_RIGHTS {
+            assert!(Rights::Owner.can_modify(target), "Owner should manage {target:?}");
+        }
+    }
+
+    #[test]
+    fn admin_can_only_modify_strictly_lower_rights() {
+        assert!(Rights::Admin.can_modify(Rights::Member));
+        assert!(Rights::Admin.can_modify(Rights::Revoked));
+        assert!(!Rights::Admin.can_modify(Rights::Admin));
+        assert!(!Rights::Admin.can_modify(Rights::Owner));
+    }
+
+    #[test]
+    fn member_and_revoked_cannot_modify_peers_at_or_above_themselves() {
+        assert!(!Rights::Member.can_modify(Rights::Member));
+        assert!(!Rights::Member.can_modify(Rights::Admin));
+        assert!(!Rights::Member.can_modify(Rights::Owner));
+        assert!(!Rights::Revoked.can_modify(Rights::Revoked));
+    }
+
+    #[test]
+    fn at_least_treats_revoked_as_below_every_rung() {
+        assert!(!Rights::Revoked.at_least(Rights::Revoked));
+        assert!(Rights::Member.at_least(Rights::Member));
+        assert!(Rights::Admin.at_least(Rights::Member));
+        assert!(!Rights::Member.at_least(Rights::Admin));
+    }
+
+    #[test]
+    fn rights_of_defaults_to_revoked_for_unknown_pubkey() {
+        let group = Group {
+            creator: Pubkey::default(),
+            name: "test".to_string(),
+            group_key: [0u8; 32],
+            key_epoch: 0,
+            members: vec![Member {
+                pubkey: Pubkey::default(),
+                rights: Rights::Owner,
+            }],
+            bump: 0,
+        };
+        let stranger = Pubkey::new_unique();
+        assert_eq!(group.rights_of(&stranger), Rights::Revoked);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod directory;
+pub mod groups;
+pub mod recovery;
+pub mod username;
+
+pub use directory::*;
+pub use groups::*;
+pub use recovery::*;
+pub use username::*;
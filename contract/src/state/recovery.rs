@@ -0,0 +1,159 @@
+use crate::constants::DISCRIMINATOR_LENGTH;
+use anchor_lang::prelude::*;
+
+pub const MAX_GUARDIANS: usize = 10;
+
+/// Owner-configured guardian set for social recovery. Stored inline on
+/// `UsernameAccount` rather than a separate PDA since it's small, bounded,
+/// and always read together with the account it protects.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl RecoveryConfig {
+    pub const LEN: usize = 4 + 32 * MAX_GUARDIANS + 1;
+
+    pub fn is_guardian(&self, pubkey: &Pubkey) -> bool {
+        self.guardians.contains(pubkey)
+    }
+
+    /// A threshold is only sane if it requires at least one guardian and no
+    /// more than the guardian set actually configured.
+    pub fn is_threshold_valid(guardians_len: usize, threshold: u8) -> bool {
+        threshold >= 1 && (threshold as usize) <= guardians_len
+    }
+}
+
+/// Tracks an in-flight recovery attempt for a single `UsernameAccount`. Only
+/// one can be live at a time since it's a PDA seeded by that account's key;
+/// a new proposal can't be created until the previous one is finalized or
+/// cancelled.
+#[account]
+pub struct RecoveryRequest {
+    pub username_account: Pubkey,
+    pub new_owner: Pubkey,
+    pub new_encryption_key: [u8; 32],
+    pub approvals: Vec<Pubkey>,
+    pub expiry_slot: u64,
+    pub bump: u8,
+}
+
+impl RecoveryRequest {
+    pub const LEN: usize = DISCRIMINATOR_LENGTH
+        + 32 // username_account
+        + 32 // new_owner
+        + 32 // new_encryption_key
+        + 4 + 32 * MAX_GUARDIANS // approvals
+        + 8 // expiry_slot
+        + 1; // bump
+
+    /// A proposed expiry must lie strictly in the future, and no further out
+    /// than `max_window_slots`, so a malicious proposer can't squat the
+    /// single recovery slot for an account indefinitely.
+    pub fn is_expiry_valid(expiry_slot: u64, current_slot: u64, max_window_slots: u64) -> bool {
+        expiry_slot > current_slot && expiry_slot <= current_slot.saturating_add(max_window_slots)
+    }
+
+    pub fn is_expired(&self, current_slot: u64) -> bool {
+        current_slot > self.expiry_slot
+    }
+
+    /// Counts approvals that still belong to `config`'s guardian set. A
+    /// guardian removed by `configure_recovery` after approving a pending
+    /// request must not keep contributing to its threshold.
+    pub fn valid_approval_count(&self, config: &RecoveryConfig) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approval| config.is_guardian(approval))
+            .count()
+    }
+
+    pub fn has_reached_threshold(&self, config: &RecoveryConfig) -> bool {
+        self.valid_approval_count(config) >= config.threshold as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold_must_be_between_one_and_guardian_count() {
+        assert!(!RecoveryConfig::is_threshold_valid(3, 0));
+        assert!(RecoveryConfig::is_threshold_valid(3, 1));
+        assert!(RecoveryConfig::is_threshold_valid(3, 3));
+        assert!(!RecoveryConfig::is_threshold_valid(3, 4));
+        assert!(!RecoveryConfig::is_threshold_valid(0, 1));
+    }
+
+    #[test]
+    fn expiry_must_be_strictly_future_and_within_window() {
+        assert!(!RecoveryRequest::is_expiry_valid(100, 100, 1_000));
+        assert!(!RecoveryRequest::is_expiry_valid(99, 100, 1_000));
+        assert!(RecoveryRequest::is_expiry_valid(101, 100, 1_000));
+        assert!(RecoveryRequest::is_expiry_valid(1_100, 100, 1_000));
+        assert!(!RecoveryRequest::is_expiry_valid(1_101, 100, 1_000));
+        // An expiry at u64::MAX lies far past the window and is rejected.
+        assert!(!RecoveryRequest::is_expiry_valid(u64::MAX, 100, 1_000));
+    }
+
+    fn request_with_approvals(approvals: Vec<Pubkey>) -> RecoveryRequest {
+        RecoveryRequest {
+            username_account: Pubkey::default(),
+            new_owner: Pubkey::default(),
+            new_encryption_key: [0u8; 32],
+            approvals,
+            expiry_slot: 1_000,
+            bump: 0,
+        }
+    }
+
+    fn unique_approvals(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    fn config_with_guardians(guardians: Vec<Pubkey>, threshold: u8) -> RecoveryConfig {
+        RecoveryConfig {
+            guardians,
+            threshold,
+        }
+    }
+
+    #[test]
+    fn threshold_met_requires_enough_distinct_approvals() {
+        let approvals = unique_approvals(2);
+        let request = request_with_approvals(approvals.clone());
+
+        assert!(!request.has_reached_threshold(&config_with_guardians(approvals.clone(), 3)));
+        assert!(request.has_reached_threshold(&config_with_guardians(approvals.clone(), 2)));
+        assert!(request.has_reached_threshold(&config_with_guardians(approvals, 1)));
+    }
+
+    #[test]
+    fn revoked_guardian_approvals_no_longer_count_toward_threshold() {
+        let approvals = unique_approvals(3);
+        let request = request_with_approvals(approvals.clone());
+
+        // All three approvers are still guardians: threshold of 3 is met.
+        let full_config = config_with_guardians(approvals.clone(), 3);
+        assert_eq!(request.valid_approval_count(&full_config), 3);
+        assert!(request.has_reached_threshold(&full_config));
+
+        // The owner revokes one guardian (e.g. a compromised key) via
+        // `configure_recovery`; that guardian's stale approval must drop out
+        // of the count even though it's still recorded on the request.
+        let reduced_config = config_with_guardians(approvals[1..].to_vec(), 3);
+        assert_eq!(request.valid_approval_count(&reduced_config), 2);
+        assert!(!request.has_reached_threshold(&reduced_config));
+    }
+
+    #[test]
+    fn is_expired_compares_strictly_past_expiry_slot() {
+        let request = request_with_approvals(Vec::new());
+        assert!(!request.is_expired(1_000));
+        assert!(!request.is_expired(999));
+        assert!(request.is_expired(1_001));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::constants::{DISCRIMINATOR_LENGTH, MAX_KEY_HISTORY};
+use crate::state::RecoveryConfig;
+use anchor_lang::prelude::*;
+
+/// A single historical encryption key, recorded at the slot it became active
+/// so a peer can match a cached epoch to the key that was valid at the time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct KeyHistoryEntry {
+    pub epoch: u64,
+    pub key: [u8; 32],
+    pub activated_at_slot: u64,
+}
+
+impl KeyHistoryEntry {
+    pub const LEN: usize = 8 + 32 + 8;
+}
+
+#[account]
+pub struct UsernameAccount {
+    pub owner: Pubkey,
+    pub username: String,
+    /// The currently active encryption key; kept alongside `key_history` so
+    /// existing readers of the "current key" don't need to know about epochs.
+    pub encryption_key: [u8; 32],
+    /// Monotonically increasing counter bumped on every rotation.
+    pub key_epoch: u64,
+    /// Bounded ring buffer of the last `MAX_KEY_HISTORY` keys, oldest entries
+    /// overwritten once full. `key_history_len` tracks how many slots are
+    /// populated and `key_history_head` is the index of the oldest entry.
+    pub key_history: [KeyHistoryEntry; MAX_KEY_HISTORY],
+    pub key_history_len: u8,
+    pub key_history_head: u8,
+    /// Owner-configured guardian set for `propose_recovery` /
+    /// `approve_recovery` / `finalize_recovery`. `None` until the owner opts
+    /// in via `configure_recovery`.
+    pub recovery_config: Option<RecoveryConfig>,
+    pub bump: u8,
+}
+
+impl UsernameAccount {
+    pub const MAX_USERNAME_LEN: usize = 32;
+
+    pub const LEN: usize = DISCRIMINATOR_LENGTH
+        + 32 // owner
+        + 4 + Self::MAX_USERNAME_LEN // username
+        + 32 // encryption_key
+        + 8 // key_epoch
+        + KeyHistoryEntry::LEN * MAX_KEY_HISTORY
+        + 1 // key_history_len
+        + 1 // key_history_head
+        + 1 + RecoveryConfig::LEN // recovery_config
+        + 1; // bump
+
+    /// Records `new_key` as the active key for a freshly bumped epoch,
+    /// appending that new epoch's entry to the ring buffer. Overwrites the oldest
+    /// entry once the buffer is full rather than growing unbounded.
+    pub fn rotate_key(&mut self, new_key: [u8; 32], activated_at_slot: u64) {
+        self.key_epoch = self.key_epoch.checked_add(1).unwrap();
+        self.encryption_key = new_key;
+
+        let entry = KeyHistoryEntry {
+            epoch: self.key_epoch,
+            key: new_key,
+            activated_at_slot,
+        };
+
+        let len = self.key_history_len as usize;
+        if len < MAX_KEY_HISTORY {
+            let index = (self.key_history_head as usize + len) % MAX_KEY_HISTORY;
+            self.key_history[index] = entry;
+            self.key_history_len += 1;
+        } else {
+            self.key_history[self.key_history_head as usize] = entry;
+            self.key_history_head = ((self.key_history_head as usize + 1) % MAX_KEY_HISTORY) as u8;
+        }
+    }
+
+    /// Looks up the key that was active at `epoch`, searching the ring
+    /// buffer. Returns `None` once the entry has aged out of the history.
+    pub fn key_at_epoch(&self, epoch: u64) -> Option<[u8; 32]> {
+        if epoch == self.key_epoch {
+            return Some(self.encryption_key);
+        }
+        for i in 0..self.key_history_len as usize {
+            let index = (self.key_history_head as usize + i) % MAX_KEY_HISTORY;
+            let entry = &self.key_history[index];
+            if entry.epoch == epoch {
+                return Some(entry.key);
+            }
+        }
+        None
+    }
+
+    /// True if `pubkey` may cancel a still-pending recovery request for this
+    /// account: the owner or one of the guardians they've configured. Used
+    /// to let either of them reclaim a squatted `RecoveryRequest` slot early
+    /// instead of waiting out a griefer's full expiry window.
+    pub fn can_cancel_pending_recovery(&self, pubkey: &Pubkey) -> bool {
+        self.owner == *pubkey
+            || self
+                .recovery_config
+                .as_ref()
+                .is_some_and(|config| config.is_guardian(pubkey))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh_account() -> UsernameAccount {
+        UsernameAccount {
+            owner: Pubkey::default(),
+            username: "alice".to_string(),
+            encryption_key: [0u8; 32],
+            key_epoch: 0,
+            key_history: [KeyHistoryEntry::default(); MAX_KEY_HISTORY],
+            key_history_len: 1,
+            key_history_head: 0,
+            recovery_config: None,
+            bump: 0,
+        }
+        .with_initial_history()
+    }
+
+    impl UsernameAccount {
+        fn with_initial_history(mut self) -> Self {
+            self.key_history[0] = KeyHistoryEntry {
+                epoch: 0,
+                key: self.encryption_key,
+                activated_at_slot: 0,
+            };
+            self
+        }
+    }
+
+    #[test]
+    fn key_at_epoch_finds_current_and_history() {
+        let mut account = fresh_account();
+        account.rotate_key([1u8; 32], 100);
+        account.rotate_key([2u8; 32], 200);
+
+        assert_eq!(account.key_epoch, 2);
+        assert_eq!(account.key_at_epoch(2), Some([2u8; 32]));
+        assert_eq!(account.key_at_epoch(1), Some([1u8; 32]));
+        assert_eq!(account.key_at_epoch(0), Some([0u8; 32]));
+        assert_eq!(account.key_at_epoch(99), None);
+    }
+
+    #[test]
+    fn ring_buffer_overwrites_oldest_once_full() {
+        let mut account = fresh_account();
+        // The initial registration (epoch 0) already occupies one slot, so
+        // only MAX_KEY_HISTORY - 1 further rotations fit before the buffer
+        // is full without evicting anything.
+        for i in 0..MAX_KEY_HISTORY as u64 - 1 {
+            account.rotate_key([i as u8; 32], i);
+        }
+        assert_eq!(account.key_history_len as usize, MAX_KEY_HISTORY);
+
+        // Epoch 0 (the initial registration key) is still within the last
+        // MAX_KEY_HISTORY entries.
+        assert_eq!(account.key_at_epoch(0), Some([0u8; 32]));
+
+        // One more rotation pushes epoch 0 out of the buffer.
+        account.rotate_key([99u8; 32], 999);
+        assert_eq!(account.key_at_epoch(0), None);
+        assert_eq!(account.key_at_epoch(account.key_epoch), Some([99u8; 32]));
+        assert_eq!(account.key_history_len as usize, MAX_KEY_HISTORY);
+    }
+
+    #[test]
+    fn rotate_key_always_bumps_epoch_monotonically() {
+        let mut account = fresh_account();
+        let mut previous_epoch = account.key_epoch;
+        for i in 0..20u8 {
+            account.rotate_key([i; 32], i as u64);
+            assert!(account.key_epoch > previous_epoch);
+            previous_epoch = account.key_epoch;
+        }
+    }
+
+    #[test]
+    fn can_cancel_pending_recovery_allows_owner_and_guardians_only() {
+        let owner = Pubkey::new_unique();
+        let guardian = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+
+        let mut account = fresh_account();
+        account.owner = owner;
+        account.recovery_config = Some(RecoveryConfig {
+            guardians: vec![guardian],
+            threshold: 1,
+        });
+
+        assert!(account.can_cancel_pending_recovery(&owner));
+        assert!(account.can_cancel_pending_recovery(&guardian));
+        assert!(!account.can_cancel_pending_recovery(&stranger));
+    }
+
+    #[test]
+    fn can_cancel_pending_recovery_owner_only_without_recovery_configured() {
+        let owner = Pubkey::new_unique();
+        let mut account = fresh_account();
+        account.owner = owner;
+
+        assert!(account.can_cancel_pending_recovery(&owner));
+        assert!(!account.can_cancel_pending_recovery(&Pubkey::new_unique()));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::errors::KeyRegistryError;
+use anchor_lang::prelude::*;
+
+/// Shared validation so every instruction that accepts a username enforces
+/// the same length bound instead of re-deriving it ad hoc.
+pub fn validate_username(username: &str) -> Result<()> {
+    require!(
+        !username.is_empty() && username.len() <= 32,
+        KeyRegistryError::InvalidUsernameLength
+    );
+    Ok(())
+}